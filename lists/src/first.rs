@@ -1,38 +1,127 @@
-use std::mem;
+use std::rc::Rc;
 
 pub struct List<T> {
 	head: Link<T>,
 }
 
-enum Link<T> {
-	Empty,
-	More(Box<Node<T>>),
-}
+type Link<T> = Option<Rc<Node<T>>>;
 
 struct Node<T> {
 	elem: T,
 	next: Link<T>,
 }
 
+impl<T> Default for List<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 impl<T> List<T> {
-	pub fn push(&mut self, elem: T) {
-		let new_node = Box::new(Node {
-			elem: elem,
-			next: mem::replace(&mut self.head, Link::Empty),
-		});
+	pub fn new() -> Self {
+		List { head: None }
+	}
 
-		self.head = Link::More(new_node);
+	// Shares `self`'s whole chain as the new list's tail instead of copying it.
+	pub fn prepend(&self, elem: T) -> List<T> {
+		List {
+			head: Some(Rc::new(Node { elem, next: self.head.clone() })),
+		}
 	}
 
-	pub fn pop(&mut self) -> Option<T> {
-		match self.head {
-			Link::Empty => {
-				// TODO
-			}
-			Link::More(ref node) => {
-				// TODO
+	pub fn tail(&self) -> List<T> {
+		List {
+			head: self.head.as_ref().and_then(|node| node.next.clone()),
+		}
+	}
+
+	pub fn head(&self) -> Option<&T> {
+		self.head.as_ref().map(|node| &node.elem)
+	}
+
+	pub fn iter(&self) -> Iter<'_, T> {
+		Iter { next: self.head.as_deref() }
+	}
+}
+
+impl<T> Drop for List<T> {
+	fn drop(&mut self) {
+		let mut link = self.head.take();
+		while let Some(node) = link {
+			match Rc::try_unwrap(node) {
+				// We're the last list referencing this node, so it's ours
+				// to free — walk down to the next one and keep going.
+				Ok(mut node) => link = node.next.take(),
+				// Some other list still shares this node (and everything
+				// below it), so stop without touching it.
+				Err(_) => break,
 			}
-		};
-		unimplemented!()
+		}
+	}
+}
+
+pub struct Iter<'a, T> {
+	next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.next.map(|node| {
+			self.next = node.next.as_deref();
+			&node.elem
+		})
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::List;
+
+	#[test]
+	fn prepend_and_tail_share_structure() {
+		let list = List::new().prepend(1).prepend(2).prepend(3);
+		assert_eq!(list.head(), Some(&3));
+
+		let tail = list.tail();
+		assert_eq!(tail.head(), Some(&2));
+		assert_eq!(list.head(), Some(&3));
+
+		let branch = tail.prepend(4);
+		assert_eq!(branch.iter().copied().collect::<Vec<_>>(), vec![4, 2, 1]);
+		assert_eq!(tail.iter().copied().collect::<Vec<_>>(), vec![2, 1]);
+
+		let empty = List::<i32>::new().tail();
+		assert_eq!(empty.head(), None);
+	}
+
+	#[test]
+	fn iter_walks_in_order() {
+		let list = List::new().prepend(1).prepend(2).prepend(3);
+
+		let mut iter = list.iter();
+		assert_eq!(iter.next(), Some(&3));
+		assert_eq!(iter.next(), Some(&2));
+		assert_eq!(iter.next(), Some(&1));
+		assert_eq!(iter.next(), None);
+	}
+
+	#[test]
+	fn dropping_a_shared_branch_leaves_the_other_intact() {
+		let trunk = List::new().prepend(1).prepend(2);
+		let branch = trunk.prepend(3);
+
+		drop(branch);
+
+		assert_eq!(trunk.iter().copied().collect::<Vec<_>>(), vec![2, 1]);
+	}
+
+	#[test]
+	fn long_list_does_not_overflow_the_stack_on_drop() {
+		let mut list = List::new();
+		for i in 0..100_000 {
+			list = list.prepend(i);
+		}
 	}
 }
@@ -0,0 +1,8 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod silly1;
+pub mod silly2;
+
+// Built on `std::rc::Rc` directly, so it only makes sense under `std`.
+#[cfg(feature = "std")]
+pub mod first;
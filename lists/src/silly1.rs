@@ -1,8 +1,25 @@
+// `Stack`/`List` here don't touch anything `std`-only, so under the crate's
+// `no_std` + `alloc` feature (see lib.rs/Cargo.toml) this module only needs
+// to pull `Box`/`Vec` from `alloc` instead of `std`.
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+use std::{boxed::Box, vec::IntoIter as VecIntoIter, vec::Vec};
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec::IntoIter as VecIntoIter, vec::Vec};
+
 pub struct List<T> {
     left: Stack<T>,
     right: Stack<T>,
 }
 
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> List<T> {
     pub fn new() -> Self {
         List { left: Stack::new(), right: Stack::new() }
@@ -28,6 +45,200 @@ impl<T> List<T> {
             self.left.push_node(node);
         }).is_some()
     }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        // `left` is stored nearest-gap-first, the reverse of logical order,
+        // so it has to be buffered and flipped; `right` is already
+        // gap-outward (logical order), so it's walked directly instead.
+        let mut left_buf: Vec<&T> = Vec::new();
+        let mut cur = self.left.head.as_deref();
+        while let Some(node) = cur {
+            left_buf.push(&node.elem);
+            cur = node.next.as_deref();
+        }
+        left_buf.reverse();
+
+        Iter {
+            left: left_buf.into_iter(),
+            right: RightCursor::Linked(self.right.head.as_deref()),
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        // Unlike `iter`, `right` isn't walked lazily here: every `&mut`,
+        // from both stacks, is collected into one buffer up front, and the
+        // iterator just drains that buffer from either end.
+        let mut elems: Vec<&mut T> = Vec::new();
+
+        let mut cur = self.left.head.as_deref_mut();
+        while let Some(node) = cur {
+            let Node { elem, next } = node;
+            elems.push(elem);
+            cur = next.as_deref_mut();
+        }
+        elems.reverse();
+
+        let mut cur = self.right.head.as_deref_mut();
+        while let Some(node) = cur {
+            let Node { elem, next } = node;
+            elems.push(elem);
+            cur = next.as_deref_mut();
+        }
+
+        IterMut { inner: elems.into_iter() }
+    }
+
+    // Cleaves the deque at the cursor: left of the gap becomes one list,
+    // at/right of it becomes the other. O(1), no node moves.
+    pub fn split(self) -> (List<T>, List<T>) {
+        (
+            List { left: self.left, right: Stack::new() },
+            List { left: Stack::new(), right: self.right },
+        )
+    }
+
+    // Splices `other` in at the cursor; the new cursor ends up where
+    // `other`'s was. Only walks `other`'s nodes.
+    pub fn append(&mut self, other: List<T>) {
+        self.left.splice_on_top(other.left);
+        self.right.splice_on_top(other.right);
+    }
+}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(mut self) -> IntoIter<T> {
+        // Same asymmetry as `iter`: `left` needs buffering to reverse it,
+        // `right` is drained lazily (it already owns its nodes, so draining
+        // is just repeated `pop`s) instead of collected up front.
+        let mut left_buf = Vec::new();
+        while let Some(elem) = self.left.pop() {
+            left_buf.push(elem);
+        }
+        left_buf.reverse();
+
+        IntoIter {
+            left: left_buf.into_iter(),
+            right: RightIntoCursor::Linked(self.right),
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    left: VecIntoIter<&'a T>,
+    right: RightCursor<'a, T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.left.next().or_else(|| self.right.next())
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.right.next_back().or_else(|| self.left.next_back())
+    }
+}
+
+// Walks `right`'s chain directly (no allocation) as long as only `next` is
+// used; only buffers (and only the as-yet-unvisited remainder) the first
+// time `next_back` needs to pop from the far end of a forward-only chain.
+enum RightCursor<'a, T> {
+    Linked(Option<&'a Node<T>>),
+    Buffered(VecIntoIter<&'a T>),
+}
+
+impl<'a, T> RightCursor<'a, T> {
+    fn next(&mut self) -> Option<&'a T> {
+        match self {
+            RightCursor::Linked(cur) => cur.take().map(|node| {
+                *cur = node.next.as_deref();
+                &node.elem
+            }),
+            RightCursor::Buffered(iter) => iter.next(),
+        }
+    }
+
+    fn next_back(&mut self) -> Option<&'a T> {
+        if let RightCursor::Linked(cur) = self {
+            let mut elems = Vec::new();
+            let mut node = cur.take();
+            while let Some(n) = node {
+                elems.push(&n.elem);
+                node = n.next.as_deref();
+            }
+            *self = RightCursor::Buffered(elems.into_iter());
+        }
+        match self {
+            RightCursor::Buffered(iter) => iter.next_back(),
+            RightCursor::Linked(_) => unreachable!(),
+        }
+    }
+}
+
+pub struct IterMut<'a, T> {
+    inner: VecIntoIter<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> { self.inner.next() }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> { self.inner.next_back() }
+}
+
+pub struct IntoIter<T> {
+    left: VecIntoIter<T>,
+    right: RightIntoCursor<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.left.next().or_else(|| self.right.next())
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.right.next_back().or_else(|| self.left.next_back())
+    }
+}
+
+// Same deferred-buffering trick as `RightCursor`, but draining an owned
+// `Stack<T>` (via `pop`) rather than walking borrowed nodes.
+enum RightIntoCursor<T> {
+    Linked(Stack<T>),
+    Buffered(VecIntoIter<T>),
+}
+
+impl<T> RightIntoCursor<T> {
+    fn next(&mut self) -> Option<T> {
+        match self {
+            RightIntoCursor::Linked(stack) => stack.pop(),
+            RightIntoCursor::Buffered(iter) => iter.next(),
+        }
+    }
+
+    fn next_back(&mut self) -> Option<T> {
+        if let RightIntoCursor::Linked(stack) = self {
+            let mut elems = Vec::new();
+            while let Some(elem) = stack.pop() {
+                elems.push(elem);
+            }
+            *self = RightIntoCursor::Buffered(elems.into_iter());
+        }
+        match self {
+            RightIntoCursor::Buffered(iter) => iter.next_back(),
+            RightIntoCursor::Linked(_) => unreachable!(),
+        }
+    }
 }
 
 
@@ -48,16 +259,19 @@ struct Node<T> {
     next: Link<T>,
 }
 
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Stack<T> {
     pub fn new() -> Self {
         Stack { head: None }
     }
 
     pub fn push(&mut self, elem: T) {
-        let new_node = Box::new(Node {
-            elem: elem,
-            next: None,
-        });
+        let new_node = Box::new(Node { elem, next: None });
 
         self.push_node(new_node);
     }
@@ -91,6 +305,21 @@ impl<T> Stack<T> {
             &mut node.elem
         })
     }
+
+    // Links `on_top`'s chain onto `self`'s old head and makes it the new
+    // head. Only walks `on_top`, never `self`'s existing chain.
+    fn splice_on_top(&mut self, mut on_top: Stack<T>) {
+        if let Some(mut head) = on_top.head.take() {
+            {
+                let mut tail = &mut head;
+                while tail.next.is_some() {
+                    tail = tail.next.as_mut().unwrap();
+                }
+                tail.next = self.head.take();
+            }
+            self.head = Some(head);
+        }
+    }
 }
 
 impl<T> Drop for Stack<T> {
@@ -138,6 +367,101 @@ mod test {
         assert_eq!(list.pop_left(), None);
 
     }
+
+    fn fixture() -> List<i32> {
+        let mut list = List::new();          // [_]
+
+        list.push_left(0);                  // [0, _]
+        list.push_right(1);                 // [0, _, 1]
+        list.push_left(2);                  // [0, 2, _, 1]
+        list.push_left(3);                  // [0, 2, 3, _, 1]
+        list.push_right(4);                 // [0, 2, 3, _, 4, 1]
+        // logical order: 0, 2, 3, 4, 1
+
+        list
+    }
+
+    #[test]
+    fn iter_forward_and_backward() {
+        let list = fixture();
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 2, 3, 4, 1]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![1, 4, 3, 2, 0]);
+    }
+
+    #[test]
+    fn iter_converges_from_both_ends() {
+        let list = fixture();
+        let mut iter = list.iter();
+
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next_back(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn iter_mut_converges_from_both_ends() {
+        let mut list = fixture();
+
+        {
+            let mut iter = list.iter_mut();
+            assert_eq!(iter.next(), Some(&mut 0));
+            assert_eq!(iter.next_back(), Some(&mut 1));
+            for elem in iter {
+                *elem *= 10;
+            }
+        }
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 20, 30, 40, 1]);
+    }
+
+    #[test]
+    fn into_iter_converges_from_both_ends() {
+        let list = fixture();
+        let mut iter = list.into_iter();
+
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next_back(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn split_cleaves_at_the_cursor() {
+        let list = fixture(); // [0, 2, 3, _, 4, 1]
+
+        let (left, right) = list.split();
+        assert_eq!(left.iter().copied().collect::<Vec<_>>(), vec![0, 2, 3]);
+        assert_eq!(right.iter().copied().collect::<Vec<_>>(), vec![4, 1]);
+    }
+
+    #[test]
+    fn append_splices_at_the_cursor() {
+        let mut list = fixture(); // [0, 2, 3, _, 4, 1]
+
+        let mut other = List::new();
+        other.push_left(10);
+        other.push_left(11); // [10, 11, _]
+        // other's logical order: 10, 11
+
+        list.append(other);
+
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![0, 2, 3, 10, 11, 4, 1]
+        );
+
+        // The new cursor sits where `other`'s cursor was.
+        assert_eq!(list.pop_left(), Some(11));
+        assert_eq!(list.pop_right(), Some(4));
+    }
 }
 
 
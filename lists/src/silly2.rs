@@ -33,6 +33,41 @@ impl<'a, T> Iterator for Iter<'a, T> {
     }
 }
 
+impl<'a, T: Copy> List<'a, T> {
+    // Rebuilds the chain in reversed order, entirely on the stack, and
+    // hands the reversed list's tail to `callback`.
+    pub fn reverse<U>(&'a self, callback: impl for<'r> FnOnce(&'r List<'r, T>) -> U) -> U {
+        fn go<'n, T: Copy, U>(
+            node: &'n List<'n, T>,
+            acc: Option<&'n List<'n, T>>,
+            callback: impl for<'r> FnOnce(&'r List<'r, T>) -> U,
+        ) -> U {
+            match node.prev {
+                Some(rest) => {
+                    List::push(acc, node.data, |new_acc| go(rest, Some(new_acc), callback))
+                }
+                None => List::push(acc, node.data, |list| callback(list)),
+            }
+        }
+
+        go(self, None, callback)
+    }
+
+    // Removes the deepest element by reversing twice, with the removed
+    // value peeled off in between.
+    pub fn pop_back<U>(
+        &'a self,
+        callback: impl for<'r> FnOnce(Option<&'r List<'r, T>>, Option<T>) -> U,
+    ) -> U {
+        self.reverse(|reversed| match reversed.prev {
+            None => callback(None, Some(reversed.data)),
+            Some(rest) => rest.reverse(|double_reversed| {
+                callback(Some(double_reversed), Some(reversed.data))
+            }),
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::List;
@@ -72,4 +107,44 @@ mod test {
             })
         })
     }
+
+    #[test]
+    fn reverse_rebuilds_the_chain_backwards() {
+        List::push(None, 1, |list| {
+            List::push(Some(list), 2, |list| {
+                List::push(Some(list), 3, |list| {
+                    assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+
+                    list.reverse(|reversed| {
+                        assert_eq!(reversed.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+                    })
+                })
+            })
+        })
+    }
+
+    #[test]
+    fn pop_back_removes_the_deepest_element() {
+        List::push(None, 1, |list| {
+            List::push(Some(list), 2, |list| {
+                List::push(Some(list), 3, |list| {
+                    list.pop_back(|rest, popped| {
+                        assert_eq!(popped, Some(1));
+                        let rest = rest.expect("3 and 2 remain");
+                        assert_eq!(rest.iter().copied().collect::<Vec<_>>(), vec![3, 2]);
+                    })
+                })
+            })
+        })
+    }
+
+    #[test]
+    fn pop_back_on_a_single_node_empties_the_list() {
+        List::push(None, 42, |list| {
+            list.pop_back(|rest, popped| {
+                assert_eq!(popped, Some(42));
+                assert!(rest.is_none());
+            })
+        })
+    }
 }
\ No newline at end of file